@@ -1,12 +1,71 @@
 use std::process::{Command, Stdio, Child};
 use std::sync::{Mutex, Arc};
+use std::sync::mpsc::{self, Receiver, Sender};
 use std::thread;
 use std::time::{Duration, Instant};
+use std::io::{BufRead, BufReader, Write};
+#[cfg(target_family = "unix")]
+use std::os::unix::net::UnixStream;
 use lazy_static::lazy_static;
 
+#[cfg(target_family = "unix")]
+const MPV_SOCKET_PATH: &str = "/tmp/mpvsocket";
+#[cfg(target_family = "windows")]
+const MPV_SOCKET_PATH: &str = r"\\.\pipe\mpvsocket";
+/// How far from the end of the current track we enqueue the next one, so
+/// mpv's own gapless playlist advance covers the transition.
+const PRELOAD_SECONDS_BEFORE_END: u64 = 30;
+
+/// The IPC transport: a Unix domain socket on unix, a named pipe handle on
+/// Windows. Both are full-duplex and support `try_clone()`, so `connect_ipc`
+/// and `send_ipc_command` below don't need to know which one they have.
+#[cfg(target_family = "unix")]
+type IpcStream = UnixStream;
+#[cfg(target_family = "windows")]
+type IpcStream = std::fs::File;
+
 lazy_static! {
     static ref AUDIO_CHILD: Mutex<Option<Child>> = Mutex::new(None);
     static ref PLAYBACK_STATE: Mutex<PlaybackState> = Mutex::new(PlaybackState::default());
+    static ref IPC_SOCKET: Mutex<Option<IpcStream>> = Mutex::new(None);
+    static ref NEXT_PRELOADED: Mutex<bool> = Mutex::new(false);
+    static ref EVENT_SENDERS: Mutex<Vec<Sender<PlaybackEvent>>> = Mutex::new(Vec::new());
+    static ref CROSSFADE_SECS: Mutex<f32> = Mutex::new(0.0);
+    static ref OUTGOING_CHILD: Mutex<Option<Child>> = Mutex::new(None);
+    static ref OUTGOING_SOCKET: Mutex<Option<IpcStream>> = Mutex::new(None);
+    static ref CROSSFADE_STARTED: Mutex<bool> = Mutex::new(false);
+    /// Bumped by every `connect_ipc()` call; a reader thread stops applying
+    /// its events to `PLAYBACK_STATE` once it no longer matches the latest
+    /// value, so a stream demoted to `OUTGOING_SOCKET` mid-crossfade can't
+    /// clobber the incoming track's state when it hits its own natural EOF.
+    static ref IPC_GENERATION: Mutex<u64> = Mutex::new(0);
+}
+
+/// A playback lifecycle notification, pushed to every `subscribe()`-ed
+/// receiver as it happens so a TUI, a GTK app, or an FFI bridge can react
+/// without polling `get_playback_state()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlaybackEvent {
+    Started,
+    Paused,
+    Resumed,
+    Stopped,
+    TrackEnded,
+    PositionChanged(Duration),
+    VolumeChanged(u8),
+}
+
+/// Registers a new listener for playback events. Each call gets its own
+/// channel; a dropped `Receiver` is pruned the next time an event fires.
+pub fn subscribe() -> Receiver<PlaybackEvent> {
+    let (tx, rx) = mpsc::channel();
+    EVENT_SENDERS.lock().unwrap().push(tx);
+    rx
+}
+
+fn publish(event: PlaybackEvent) {
+    let mut senders = EVENT_SENDERS.lock().unwrap();
+    senders.retain(|tx| tx.send(event.clone()).is_ok());
 }
 
 #[derive(Debug, Clone, Default)]
@@ -17,6 +76,14 @@ pub struct PlaybackState {
     pub duration: Option<Duration>,
     pub volume: u8,
     pub last_update: Option<Instant>,
+    pub ended: bool,
+    /// mpv's own `playlist-pos`, as last reported over IPC. Used to notice
+    /// when mpv has gaplessly switched to a preloaded track on its own,
+    /// since `eof-reached` does not fire for an intra-playlist transition.
+    pub last_playlist_pos: Option<i64>,
+    /// Set when `last_playlist_pos` changes to a different entry; cleared
+    /// by `take_gapless_transition()` once a caller has synced to it.
+    pub gapless_advanced: bool,
 }
 
 pub fn play_audio(file_path: &str) -> Result<(), Box<dyn std::error::Error>> {
@@ -27,8 +94,9 @@ pub fn play_audio(file_path: &str) -> Result<(), Box<dyn std::error::Error>> {
         .arg("--no-video")
         .arg("--quiet")
         .arg("--no-terminal")
-        .arg("--input-ipc-server=/tmp/mpvsocket") // Enable IPC for better control
+        .arg(format!("--input-ipc-server={}", MPV_SOCKET_PATH)) // Enable IPC for better control
         .arg("--idle=yes")
+        .arg("--gapless-audio=yes")
         .arg(file_path)
         .stdout(Stdio::null())
         .stderr(Stdio::null())
@@ -36,6 +104,7 @@ pub fn play_audio(file_path: &str) -> Result<(), Box<dyn std::error::Error>> {
 
     let mut audio_child = AUDIO_CHILD.lock().unwrap();
     *audio_child = Some(child);
+    drop(audio_child);
 
     // Update playback state
     let mut state = PLAYBACK_STATE.lock().unwrap();
@@ -43,96 +112,450 @@ pub fn play_audio(file_path: &str) -> Result<(), Box<dyn std::error::Error>> {
     state.is_paused = false;
     state.position = Duration::from_secs(0);
     state.last_update = Some(Instant::now());
+    state.ended = false;
+    state.last_playlist_pos = None;
+    state.gapless_advanced = false;
+    drop(state);
+
+    *NEXT_PRELOADED.lock().unwrap() = false;
+    *CROSSFADE_STARTED.lock().unwrap() = false;
+
+    connect_ipc();
+    publish(PlaybackEvent::Started);
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_track_started(file_path);
 
     Ok(())
 }
 
-#[cfg(target_family = "unix")]
-pub fn pause_audio() -> Result<(), Box<dyn std::error::Error>> {
-    use nix::sys::signal::{kill, Signal};
-    use nix::unistd::Pid;
-    
-    let audio_child = AUDIO_CHILD.lock().unwrap();
-    if let Some(child) = audio_child.as_ref() {
-        kill(Pid::from_raw(child.id() as i32), Signal::SIGSTOP)?;
-        
-        let mut state = PLAYBACK_STATE.lock().unwrap();
-        state.is_paused = true;
+/// Starts playback of `paths` as a single gapless queue: the first track is
+/// spawned normally and the rest are appended to mpv's own playlist so it
+/// advances between them without a stop-then-respawn gap.
+pub fn play_queue(paths: Vec<String>) -> Result<(), Box<dyn std::error::Error>> {
+    let mut paths = paths.into_iter();
+    let Some(first) = paths.next() else {
+        return Ok(());
+    };
+    play_audio(&first)?;
+    for path in paths {
+        enqueue(&path)?;
     }
     Ok(())
 }
 
+/// Appends `path` to mpv's internal playlist via `loadfile ... append`.
+pub fn enqueue(path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    send_ipc_command(&serde_json::json!({ "command": ["loadfile", path, "append"] }))
+}
+
+/// Advances mpv to the next playlist entry.
+pub fn next() -> Result<(), Box<dyn std::error::Error>> {
+    *NEXT_PRELOADED.lock().unwrap() = false;
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_skip();
+    send_ipc_command(&serde_json::json!({ "command": ["playlist-next"] }))
+}
+
+/// Moves mpv back to the previous playlist entry.
+pub fn prev() -> Result<(), Box<dyn std::error::Error>> {
+    *NEXT_PRELOADED.lock().unwrap() = false;
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_skip();
+    send_ipc_command(&serde_json::json!({ "command": ["playlist-prev"] }))
+}
+
+/// Enqueues `upcoming_path` once the current track is within
+/// `PRELOAD_SECONDS_BEFORE_END` of finishing, so the transition to it is
+/// already gapless by the time it starts. A no-op once already preloaded
+/// for the current track.
+pub fn preload_if_near_end(upcoming_path: &str) {
+    let mut preloaded = NEXT_PRELOADED.lock().unwrap();
+    if *preloaded {
+        return;
+    }
+
+    let state = PLAYBACK_STATE.lock().unwrap();
+    let Some(duration) = state.duration else {
+        return;
+    };
+    let remaining = duration.saturating_sub(state.position);
+    drop(state);
+
+    if remaining <= Duration::from_secs(PRELOAD_SECONDS_BEFORE_END) && enqueue(upcoming_path).is_ok() {
+        *preloaded = true;
+    }
+}
+
+/// Whether `preload_if_near_end` has already appended the upcoming track to
+/// mpv's playlist for the current track. Callers can use this to advance
+/// via `next()` (gapless) instead of `stop_audio` + `play_audio` (a fresh
+/// spawn with an audible gap) once this is true.
+pub fn is_preloaded() -> bool {
+    *NEXT_PRELOADED.lock().unwrap()
+}
+
+/// Clears `PLAYBACK_STATE.ended` after an app-driven gapless advance (a
+/// caller that saw `is_preloaded()` true and called `next()`). Without this
+/// the flag stays latched true and the next `update_progress` tick re-enters
+/// the same advance path again.
+pub fn mark_advanced() {
+    PLAYBACK_STATE.lock().unwrap().ended = false;
+}
+
+/// True once since the last call if mpv has switched to a different entry
+/// in its own internal playlist (observed via `playlist-pos`) — i.e. the
+/// track staged by `preload_if_near_end` actually started playing on its
+/// own, gaplessly, with no `eof-reached` in between to signal it. Clears
+/// the flag and `NEXT_PRELOADED`, since the (formerly) preloaded track is
+/// now the current one and needs its own successor staged.
+pub fn take_gapless_transition() -> bool {
+    let mut state = PLAYBACK_STATE.lock().unwrap();
+    if state.gapless_advanced {
+        state.gapless_advanced = false;
+        drop(state);
+        *NEXT_PRELOADED.lock().unwrap() = false;
+        true
+    } else {
+        false
+    }
+}
+
+/// Seconds to crossfade between consecutive tracks; `0.0` (the default)
+/// disables it and `crossfade_to` falls back to a plain `play_audio` swap.
+pub fn set_crossfade_secs(secs: f32) {
+    *CROSSFADE_SECS.lock().unwrap() = secs.max(0.0);
+}
+
+pub fn crossfade_secs() -> f32 {
+    *CROSSFADE_SECS.lock().unwrap()
+}
+
+/// Starts crossfading into `upcoming_path` once the current track is within
+/// `crossfade_secs()` of finishing, so the fade actually overlaps the
+/// outgoing track's last moments instead of starting after it's already
+/// silent. Mirrors `preload_if_near_end`'s timing check but swaps to a
+/// second mpv process via `crossfade_to` rather than queuing on this one's
+/// playlist. A no-op (returns `false`) once already started for the current
+/// track, or before crossfade is enabled/the threshold is reached — callers
+/// should only commit to advancing their own track index once this returns
+/// `true`.
+pub fn crossfade_if_near_end(upcoming_path: &str) -> bool {
+    let secs = crossfade_secs();
+    if secs <= 0.0 {
+        return false;
+    }
+
+    let mut started = CROSSFADE_STARTED.lock().unwrap();
+    if *started {
+        return false;
+    }
+
+    let state = PLAYBACK_STATE.lock().unwrap();
+    let Some(duration) = state.duration else {
+        return false;
+    };
+    let remaining = duration.saturating_sub(state.position);
+    drop(state);
+
+    if remaining > Duration::from_secs_f32(secs) {
+        return false;
+    }
+
+    *started = true;
+    drop(started);
+    crossfade_to(upcoming_path).is_ok()
+}
+
+/// Starts `file_path`, ramping it in while the currently playing track
+/// ramps out over `crossfade_secs()`, instead of cutting straight to it.
+/// Falls back to a plain stop-then-`play_audio` swap when crossfade is off
+/// or nothing is currently playing. Only called from `crossfade_if_near_end`
+/// once its threshold check passes — a manual skip calls `stop_audio` +
+/// `play_audio` directly and never reaches this.
 #[cfg(target_family = "unix")]
-pub fn resume_audio() -> Result<(), Box<dyn std::error::Error>> {
-    use nix::sys::signal::{kill, Signal};
-    use nix::unistd::Pid;
-    
-    let audio_child = AUDIO_CHILD.lock().unwrap();
-    if let Some(child) = audio_child.as_ref() {
-        kill(Pid::from_raw(child.id() as i32), Signal::SIGCONT)?;
-        
-        let mut state = PLAYBACK_STATE.lock().unwrap();
-        state.is_paused = false;
-        state.last_update = Some(Instant::now());
+fn crossfade_to(file_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let secs = crossfade_secs();
+    let master_volume = PLAYBACK_STATE.lock().unwrap().volume;
+
+    if secs <= 0.0 || AUDIO_CHILD.lock().unwrap().is_none() {
+        stop_audio();
+        return play_audio(file_path);
     }
+
+    // Hand the outgoing stream off to its own slot instead of killing it,
+    // so it keeps playing (ramping down) while the new one ramps in.
+    *OUTGOING_CHILD.lock().unwrap() = AUDIO_CHILD.lock().unwrap().take();
+    *OUTGOING_SOCKET.lock().unwrap() = IPC_SOCKET.lock().unwrap().take();
+
+    play_audio(file_path)?;
+    send_ipc_command(&serde_json::json!({ "command": ["set_property", "volume", 0] }))?;
+
+    thread::spawn(move || run_crossfade(secs, master_volume));
     Ok(())
 }
 
+/// Ramps the incoming (now-current) and outgoing streams' mpv `volume`
+/// properties in lockstep using an equal-power (`sqrt`) curve, so the
+/// crossfade's perceived loudness stays roughly constant instead of
+/// dipping in the middle, then kills the outgoing mpv process.
+#[cfg(target_family = "unix")]
+fn run_crossfade(secs: f32, master_volume: u8) {
+    const STEPS: u32 = 30;
+    let step_duration = Duration::from_secs_f32(secs / STEPS as f32);
+
+    for step in 1..=STEPS {
+        let t = step as f32 / STEPS as f32;
+        let incoming_volume = (master_volume as f32 * t.sqrt()).round() as i64;
+        let outgoing_volume = (master_volume as f32 * (1.0 - t).sqrt()).round() as i64;
+
+        let _ = send_ipc_command(
+            &serde_json::json!({ "command": ["set_property", "volume", incoming_volume] }),
+        );
+        if let Some(stream) = OUTGOING_SOCKET.lock().unwrap().as_mut() {
+            let line = format!(
+                "{{ \"command\": [\"set_property\", \"volume\", {}] }}\n",
+                outgoing_volume
+            );
+            let _ = stream.write_all(line.as_bytes());
+        }
+
+        thread::sleep(step_duration);
+    }
+
+    if let Some(mut child) = OUTGOING_CHILD.lock().unwrap().take() {
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+    *OUTGOING_SOCKET.lock().unwrap() = None;
+
+    // `play_audio`'s `stop_audio()` call at the start of the fade reset the
+    // whole `PLAYBACK_STATE` (volume included) back to its `Default` of 0;
+    // now that the incoming stream has ramped up to `master_volume`, make
+    // the state reflect that instead of reporting the track as muted.
+    PLAYBACK_STATE.lock().unwrap().volume = master_volume;
+}
+
+/// Windows has no second named-pipe handle wired up for a concurrent
+/// outgoing stream yet, so crossfade (opt-in, `crossfade_secs` defaults to
+/// `0.0`) falls back to the plain swap on this platform.
 #[cfg(target_family = "windows")]
+fn crossfade_to(file_path: &str) -> Result<(), Box<dyn std::error::Error>> {
+    stop_audio();
+    play_audio(file_path)
+}
+
+/// Writes a command to the shared `IPC_SOCKET` connection established by
+/// `connect_ipc`. Single implementation for both platforms since `IpcStream`
+/// (a `UnixStream` or a named-pipe `File`) is `Write` either way.
+fn send_ipc_command(command: &serde_json::Value) -> Result<(), Box<dyn std::error::Error>> {
+    let mut socket = IPC_SOCKET.lock().unwrap();
+    if let Some(stream) = socket.as_mut() {
+        let mut line = command.to_string();
+        line.push('\n');
+        stream.write_all(line.as_bytes())?;
+        Ok(())
+    } else {
+        Err("mpv IPC socket not connected".into())
+    }
+}
+
+/// Opens the unix domain socket mpv's `--input-ipc-server` created.
+#[cfg(target_family = "unix")]
+fn open_ipc_stream() -> std::io::Result<IpcStream> {
+    UnixStream::connect(MPV_SOCKET_PATH)
+}
+
+/// Opens mpv's named pipe for both reading and writing. `CreateFile` (which
+/// `std::fs::File::open`/`OpenOptions` use under the hood) can connect to a
+/// named pipe server like a regular file and the resulting handle supports
+/// `Read`, `Write`, and `try_clone` the same way a `UnixStream` does, so no
+/// pipe-specific crate is needed.
+#[cfg(target_family = "windows")]
+fn open_ipc_stream() -> std::io::Result<IpcStream> {
+    std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(MPV_SOCKET_PATH)
+}
+
+/// Connects to mpv's JSON-IPC socket, subscribes to the properties that
+/// drive `PLAYBACK_STATE`, and spawns a background thread that keeps
+/// reading events for as long as the connection stays open.
+///
+/// mpv needs a moment to create the socket after spawning, so this retries
+/// briefly before giving up.
+fn connect_ipc() {
+    let mut stream = None;
+    for _ in 0..20 {
+        if let Ok(s) = open_ipc_stream() {
+            stream = Some(s);
+            break;
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    let Some(mut stream) = stream else {
+        return;
+    };
+
+    for (id, property) in [
+        (1, "time-pos"),
+        (2, "duration"),
+        (3, "pause"),
+        (4, "eof-reached"),
+        (5, "playlist-pos"),
+    ] {
+        let command = format!(
+            "{{ \"command\": [\"observe_property\", {}, \"{}\"] }}\n",
+            id, property
+        );
+        let _ = stream.write_all(command.as_bytes());
+    }
+
+    let reader_stream = match stream.try_clone() {
+        Ok(s) => s,
+        Err(_) => return,
+    };
+
+    *IPC_SOCKET.lock().unwrap() = Some(stream);
+
+    let generation = {
+        let mut generation = IPC_GENERATION.lock().unwrap();
+        *generation += 1;
+        *generation
+    };
+
+    thread::spawn(move || {
+        let reader = BufReader::new(reader_stream);
+        for line in reader.lines() {
+            let Ok(line) = line else { break };
+            let Ok(event) = serde_json::from_str::<serde_json::Value>(&line) else {
+                continue;
+            };
+            if event.get("event").and_then(|e| e.as_str()) != Some("property-change") {
+                continue;
+            }
+            let Some(name) = event.get("name").and_then(|n| n.as_str()) else {
+                continue;
+            };
+
+            // A newer `connect_ipc()` call means this stream was demoted to
+            // `OUTGOING_SOCKET` by a crossfade handoff — keep draining the
+            // socket but stop writing the single shared `PLAYBACK_STATE`,
+            // otherwise its own natural EOF would trip `ended` for the
+            // incoming track.
+            if *IPC_GENERATION.lock().unwrap() != generation {
+                continue;
+            }
+
+            let mut state = PLAYBACK_STATE.lock().unwrap();
+            match name {
+                "time-pos" => {
+                    if let Some(secs) = event.get("data").and_then(|d| d.as_f64()) {
+                        let position = Duration::from_secs_f64(secs.max(0.0));
+                        state.position = position;
+                        state.last_update = Some(Instant::now());
+                        drop(state);
+                        publish(PlaybackEvent::PositionChanged(position));
+                        continue;
+                    }
+                }
+                "duration" => {
+                    state.duration = event
+                        .get("data")
+                        .and_then(|d| d.as_f64())
+                        .map(Duration::from_secs_f64);
+                }
+                "pause" => {
+                    if let Some(paused) = event.get("data").and_then(|d| d.as_bool()) {
+                        state.is_paused = paused;
+                    }
+                }
+                "eof-reached" => {
+                    if event.get("data").and_then(|d| d.as_bool()) == Some(true) {
+                        state.ended = true;
+                        state.is_playing = false;
+                        drop(state);
+                        publish(PlaybackEvent::TrackEnded);
+                        continue;
+                    }
+                }
+                "playlist-pos" => {
+                    if let Some(pos) = event.get("data").and_then(|d| d.as_i64()) {
+                        let is_new_track = state.last_playlist_pos.is_some_and(|prev| prev != pos);
+                        state.last_playlist_pos = Some(pos);
+                        if is_new_track {
+                            state.gapless_advanced = true;
+                            state.ended = false;
+                            state.position = Duration::from_secs(0);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    });
+}
+
 pub fn pause_audio() -> Result<(), Box<dyn std::error::Error>> {
-    // Windows implementation would use different approach
-    // For now, we'll use a placeholder
-    let mut state = PLAYBACK_STATE.lock().unwrap();
-    state.is_paused = true;
+    send_ipc_command(&serde_json::json!({ "command": ["set_property", "pause", true] }))?;
+    PLAYBACK_STATE.lock().unwrap().is_paused = true;
+    publish(PlaybackEvent::Paused);
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_paused();
     Ok(())
 }
 
-#[cfg(target_family = "windows")]
 pub fn resume_audio() -> Result<(), Box<dyn std::error::Error>> {
+    send_ipc_command(&serde_json::json!({ "command": ["set_property", "pause", false] }))?;
     let mut state = PLAYBACK_STATE.lock().unwrap();
     state.is_paused = false;
     state.last_update = Some(Instant::now());
+    drop(state);
+    publish(PlaybackEvent::Resumed);
+    #[cfg(feature = "metrics")]
+    crate::metrics::record_resumed();
     Ok(())
 }
 
 pub fn stop_audio() {
     let mut audio_child = AUDIO_CHILD.lock().unwrap();
+    let had_child = audio_child.is_some();
     if let Some(child) = audio_child.as_mut() {
         let _ = child.kill();
         let _ = child.wait();
     }
     *audio_child = None;
+    drop(audio_child);
+
+    *IPC_SOCKET.lock().unwrap() = None;
 
     // Reset playback state
     let mut state = PLAYBACK_STATE.lock().unwrap();
     *state = PlaybackState::default();
+    drop(state);
+
+    if had_child {
+        publish(PlaybackEvent::Stopped);
+        #[cfg(feature = "metrics")]
+        crate::metrics::record_stopped();
+    }
 }
 
 pub fn set_volume(volume: u8) -> Result<(), Box<dyn std::error::Error>> {
-    // Using mpv IPC to set volume (requires --input-ipc-server)
-    use std::os::unix::net::UnixStream;
-    use std::io::Write;
-    
-    if let Ok(mut stream) = UnixStream::connect("/tmp/mpvsocket") {
-        let command = format!("{{ \"command\": [\"set_property\", \"volume\", {}] }}\n", volume);
-        let _ = stream.write_all(command.as_bytes());
-    }
-    
+    let _ = send_ipc_command(&serde_json::json!({ "command": ["set_property", "volume", volume] }));
+
     let mut state = PLAYBACK_STATE.lock().unwrap();
     state.volume = volume;
+    drop(state);
+    publish(PlaybackEvent::VolumeChanged(volume));
     Ok(())
 }
 
 pub fn seek_to(position: Duration) -> Result<(), Box<dyn std::error::Error>> {
-    use std::os::unix::net::UnixStream;
-    use std::io::Write;
-    
-    if let Ok(mut stream) = UnixStream::connect("/tmp/mpvsocket") {
-        let seconds = position.as_secs_f64();
-        let command = format!("{{ \"command\": [\"seek\", {}, \"absolute\"] }}\n", seconds);
-        let _ = stream.write_all(command.as_bytes());
-    }
-    
+    let seconds = position.as_secs_f64();
+    let _ = send_ipc_command(&serde_json::json!({ "command": ["seek", seconds, "absolute"] }));
+
     let mut state = PLAYBACK_STATE.lock().unwrap();
     state.position = position;
     Ok(())
@@ -142,18 +565,40 @@ pub fn get_playback_state() -> PlaybackState {
     PLAYBACK_STATE.lock().unwrap().clone()
 }
 
-pub fn update_position() {
-    let mut state = PLAYBACK_STATE.lock().unwrap();
-    if state.is_playing && !state.is_paused {
-        if let Some(last_update) = state.last_update {
-            let elapsed = last_update.elapsed();
-            state.position += elapsed;
-            state.last_update = Some(Instant::now());
-        }
+/// Current playback position, as last reported by mpv's `time-pos`
+/// property, or `None` while nothing is playing.
+pub fn position() -> Option<Duration> {
+    let state = PLAYBACK_STATE.lock().unwrap();
+    if state.is_playing {
+        Some(state.position)
+    } else {
+        None
     }
 }
 
+/// Total track duration, as last reported by mpv's `duration` property (or
+/// set ahead of time via `set_known_duration`).
+pub fn total_duration() -> Option<Duration> {
+    PLAYBACK_STATE.lock().unwrap().duration
+}
+
+/// Sets the track duration from metadata known ahead of time (e.g. from
+/// yt-dlp's JSON output), instead of waiting for mpv's IPC `duration`
+/// property to arrive.
+pub fn set_known_duration(duration: Duration) {
+    PLAYBACK_STATE.lock().unwrap().duration = Some(duration);
+}
+
+/// True while mpv is alive and hasn't reported end-of-file over IPC.
+///
+/// `PLAYBACK_STATE.ended` is driven by the `eof-reached` property pushed by
+/// the IPC reader thread, so this reflects the real track-end event; the
+/// `try_wait` check on the child process only catches mpv exiting on its own.
 pub fn is_process_running() -> bool {
+    if PLAYBACK_STATE.lock().unwrap().ended {
+        return false;
+    }
+
     let audio_child = AUDIO_CHILD.lock().unwrap();
     if let Some(child) = audio_child.as_ref() {
         // Check if process is still running
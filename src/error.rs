@@ -119,18 +119,92 @@ impl From<PlaylistError> for MusicPlayerError {
 
 pub type Result<T> = std::result::Result<T, MusicPlayerError>;
 
+/// Marker trait for error variants that mean the player cannot continue and
+/// should abort, as opposed to a transient failure a caller can retry (a
+/// dropped network connection, a YouTube search that came back empty).
+pub trait FatalError {
+    fn is_fatal(&self) -> bool;
+}
+
+impl FatalError for AudioError {
+    fn is_fatal(&self) -> bool {
+        matches!(self, AudioError::MpvNotFound)
+    }
+}
+
+impl FatalError for YoutubeError {
+    fn is_fatal(&self) -> bool {
+        matches!(self, YoutubeError::YtDlpNotFound)
+    }
+}
+
+impl FatalError for PlaylistError {
+    fn is_fatal(&self) -> bool {
+        false
+    }
+}
+
+impl FatalError for MusicPlayerError {
+    fn is_fatal(&self) -> bool {
+        match self {
+            MusicPlayerError::Audio(e) => e.is_fatal(),
+            MusicPlayerError::Youtube(e) => e.is_fatal(),
+            MusicPlayerError::Playlist(e) => e.is_fatal(),
+            MusicPlayerError::Io(_) => false,
+            MusicPlayerError::Network(_) => false,
+            MusicPlayerError::Config(_) => false,
+        }
+    }
+}
+
+/// A three-state outcome that separates "the call failed but the player can
+/// keep running" from "the call failed in a way the player can't recover
+/// from", so a consumer can surface a toast-and-retry for the former and
+/// abort cleanly for the latter instead of treating every error the same.
+#[derive(Debug)]
+pub enum Severity<T> {
+    Ok(T),
+    Recoverable(MusicPlayerError),
+    Fatal(MusicPlayerError),
+}
+
+impl<T> Severity<T> {
+    pub fn is_fatal(&self) -> bool {
+        matches!(self, Severity::Fatal(_))
+    }
+}
+
+impl<T> From<std::result::Result<T, MusicPlayerError>> for Severity<T> {
+    fn from(result: std::result::Result<T, MusicPlayerError>) -> Self {
+        match result {
+            std::result::Result::Ok(value) => Severity::Ok(value),
+            Err(e) if e.is_fatal() => Severity::Fatal(e),
+            Err(e) => Severity::Recoverable(e),
+        }
+    }
+}
+
 /// Helper function to create user-friendly error messages
 pub fn user_friendly_error(error: &MusicPlayerError) -> String {
+    // Check severity first so a fatal variant without its own bespoke
+    // message below still gets the "can't recover" framing instead of
+    // silently falling into the generic "you can retry" arm.
+    if error.is_fatal() {
+        return match error {
+            MusicPlayerError::Audio(AudioError::MpvNotFound) => {
+                "🎵 Lecteur audio manquant. Installez MPV avec:\n• Ubuntu/Debian: sudo apt install mpv\n• macOS: brew install mpv\n• Windows: téléchargez depuis mpv.io".to_string()
+            },
+            MusicPlayerError::Youtube(YoutubeError::YtDlpNotFound) => {
+                "📺 Téléchargeur YouTube manquant. Installez yt-dlp avec:\n• pip install yt-dlp\n• ou visitez github.com/yt-dlp/yt-dlp".to_string()
+            },
+            _ => format!("⛔ {}\nErreur irrécupérable, l'application va s'arrêter.", error),
+        };
+    }
+
     match error {
-        MusicPlayerError::Audio(AudioError::MpvNotFound) => {
-            "🎵 Lecteur audio manquant. Installez MPV avec:\n• Ubuntu/Debian: sudo apt install mpv\n• macOS: brew install mpv\n• Windows: téléchargez depuis mpv.io".to_string()
-        },
-        MusicPlayerError::Youtube(YoutubeError::YtDlpNotFound) => {
-            "📺 Téléchargeur YouTube manquant. Installez yt-dlp avec:\n• pip install yt-dlp\n• ou visitez github.com/yt-dlp/yt-dlp".to_string()
-        },
         MusicPlayerError::Network(msg) => {
             format!("🌐 Problème de connexion: {}\nVérifiez votre connexion internet.", msg)
         },
-        _ => format!("❌ {}", error)
+        _ => format!("⚠️ {}\nVous pouvez réessayer.", error),
     }
 }
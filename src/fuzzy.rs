@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+const MATCH_BONUS: i64 = 16;
+const CONSECUTIVE_BONUS: i64 = 8;
+const BOUNDARY_BONUS: i64 = 12;
+const GAP_PENALTY: i64 = 1;
+
+fn is_separator(c: char) -> bool {
+    matches!(c, ' ' | '-' | '_' | '/' | '.')
+}
+
+/// Whether the candidate character at `index` starts a "word" — the start
+/// of the string, right after a separator, or a lower-to-upper transition
+/// (so `MyTrack` rewards a match on the `T`, not just the `M`).
+fn is_word_boundary(chars: &[char], index: usize) -> bool {
+    if index == 0 {
+        return true;
+    }
+    let prev = chars[index - 1];
+    is_separator(prev) || (prev.is_lowercase() && chars[index].is_uppercase())
+}
+
+/// Scores `candidate` against `query` as an ordered (not necessarily
+/// contiguous) subsequence match, Smith-Waterman style: characters must
+/// appear in order, consecutive runs and word-boundary matches are
+/// rewarded, and skipped candidate characters cost a small gap penalty.
+/// Returns `None` if `query` isn't a subsequence of `candidate` at all.
+pub fn score(query: &str, candidate: &str) -> Option<i64> {
+    if query.is_empty() {
+        return Some(0);
+    }
+    let q: Vec<char> = query.to_lowercase().chars().collect();
+    // Folded 1:1 against `original` (so indices line up) for the actual
+    // character comparison, but boundary detection needs the real casing —
+    // folding first would hide every lower-to-upper transition.
+    let original: Vec<char> = candidate.chars().collect();
+    let c: Vec<char> = original
+        .iter()
+        .map(|ch| ch.to_lowercase().next().unwrap_or(*ch))
+        .collect();
+    let mut memo = HashMap::new();
+    score_from(&q, &c, &original, 0, 0, false, &mut memo)
+}
+
+fn score_from(
+    q: &[char],
+    c: &[char],
+    original: &[char],
+    qi: usize,
+    ci: usize,
+    prev_matched: bool,
+    memo: &mut HashMap<(usize, usize, bool), Option<i64>>,
+) -> Option<i64> {
+    if qi == q.len() {
+        return Some(0);
+    }
+    if ci == c.len() {
+        return None;
+    }
+    if let Some(cached) = memo.get(&(qi, ci, prev_matched)) {
+        return *cached;
+    }
+
+    let mut best =
+        score_from(q, c, original, qi, ci + 1, false, memo).map(|rest| rest - GAP_PENALTY);
+
+    if q[qi] == c[ci] {
+        if let Some(rest) = score_from(q, c, original, qi + 1, ci + 1, true, memo) {
+            let mut bonus = MATCH_BONUS;
+            if prev_matched {
+                bonus += CONSECUTIVE_BONUS;
+            }
+            if is_word_boundary(original, ci) {
+                bonus += BOUNDARY_BONUS;
+            }
+            let total = bonus + rest;
+            if best.map_or(true, |b| total > b) {
+                best = Some(total);
+            }
+        }
+    }
+
+    memo.insert((qi, ci, prev_matched), best);
+    best
+}
@@ -1,14 +1,21 @@
 mod youtube;
 mod audio;
 mod playlist;
+mod error;
+mod fuzzy;
+mod tags;
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(feature = "mpris")]
+mod mpris;
 
-use std::io::{self, Write};
+use std::io;
 use std::fs;
 use std::time::{Duration, Instant};
 use crossterm::{
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
-    event::{self, Event, KeyCode, EnableMouseCapture, DisableMouseCapture},
+    event::{self, Event, KeyCode, KeyModifiers, EnableMouseCapture, DisableMouseCapture},
 };
 use ratatui::{
     backend::CrosstermBackend,
@@ -21,6 +28,155 @@ use ratatui::{
 };
 use playlist::{Track, PlaylistManager};
 
+const SEEK_STEP: Duration = Duration::from_secs(5);
+
+/// How track advancement behaves when a track ends or the user skips.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PlaybackMode {
+    Sequential,
+    RepeatOne,
+    RepeatAll,
+    Shuffle,
+}
+
+impl PlaybackMode {
+    fn next(self) -> Self {
+        match self {
+            PlaybackMode::Sequential => PlaybackMode::RepeatAll,
+            PlaybackMode::RepeatAll => PlaybackMode::RepeatOne,
+            PlaybackMode::RepeatOne => PlaybackMode::Shuffle,
+            PlaybackMode::Shuffle => PlaybackMode::Sequential,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            PlaybackMode::Sequential => "Séquentiel",
+            PlaybackMode::RepeatOne => "Répéter 1",
+            PlaybackMode::RepeatAll => "Répéter tout",
+            PlaybackMode::Shuffle => "Aléatoire",
+        }
+    }
+}
+
+/// Owns the walk order for `Shuffle` mode: a randomized permutation of the
+/// current playlist's indices, walked front-to-back so previous/next stay
+/// coherent and no track repeats until the cycle completes.
+///
+/// `next_order` is the permutation the *following* cycle will use, generated
+/// eagerly alongside `order` rather than lazily when the cursor wraps. That
+/// way `peek_next` can report the post-wrap track without guessing — it just
+/// reads `next_order[0]` — instead of re-deriving a fresh (and different)
+/// shuffle than the one `advance` ends up committing to.
+#[derive(Debug, Default)]
+struct PlaylistSequence {
+    order: Vec<usize>,
+    cursor: usize,
+    next_order: Vec<usize>,
+}
+
+impl PlaylistSequence {
+    fn reshuffle(&mut self, len: usize) {
+        self.order = shuffled_indices(len);
+        self.cursor = 0;
+        self.next_order = shuffled_indices(len);
+    }
+
+    fn advance(&mut self, len: usize) -> usize {
+        if self.order.len() != len {
+            self.reshuffle(len);
+        }
+        self.cursor += 1;
+        if self.cursor >= self.order.len() {
+            if self.next_order.len() != len {
+                self.next_order = shuffled_indices(len);
+            }
+            self.order = std::mem::take(&mut self.next_order);
+            self.cursor = 0;
+            self.next_order = shuffled_indices(len);
+        }
+        self.order.get(self.cursor).copied().unwrap_or(0)
+    }
+
+    fn retreat(&mut self, len: usize) -> usize {
+        if self.order.len() != len {
+            self.reshuffle(len);
+        }
+        self.cursor = if self.cursor == 0 {
+            self.order.len().saturating_sub(1)
+        } else {
+            self.cursor - 1
+        };
+        self.order.get(self.cursor).copied().unwrap_or(0)
+    }
+
+    /// Read-only lookahead used for preloading: the index `advance` would
+    /// return, without moving the cursor. Falls back to index 0 if the
+    /// order hasn't been shuffled for this playlist length yet — the next
+    /// real `advance` call reshuffles properly. At the cycle boundary this
+    /// reports `next_order[0]`, the same reshuffled permutation `advance`
+    /// commits to on wrap, so outside that index-0 fallback a staged
+    /// preload/crossfade track matches what actually plays next.
+    fn peek_next(&self, len: usize) -> usize {
+        if self.order.len() != len || self.order.is_empty() {
+            return 0;
+        }
+        let next_cursor = self.cursor + 1;
+        if next_cursor >= self.order.len() {
+            return self.next_order.first().copied().unwrap_or(0);
+        }
+        self.order.get(next_cursor).copied().unwrap_or(0)
+    }
+}
+
+/// A small linear congruential PRNG seeded from the clock, just enough to
+/// Fisher-Yates shuffle a playlist without pulling in a `rand` dependency.
+fn shuffled_indices(len: usize) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..len).collect();
+    let mut seed = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0x2545_F491_4F6C_DD1D)
+        | 1;
+    for i in (1..indices.len()).rev() {
+        seed = seed.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        let j = ((seed >> 33) as usize) % (i + 1);
+        indices.swap(i, j);
+    }
+    indices
+}
+
+/// Renders a `Duration` as `mm:ss` for the playlist list and header.
+fn format_duration(duration: Duration) -> String {
+    let total_secs = duration.as_secs();
+    format!("{:02}:{:02}", total_secs / 60, total_secs % 60)
+}
+
+/// What a `BrowserState` popup is asking the user to pick a playlist for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BrowserPurpose {
+    SwitchPlaylist,
+    AddTrackTo,
+    DeletePlaylist,
+}
+
+/// State for the navigable playlist-picker popup that replaces the old
+/// blocking `prompt()` calls for switching/adding-to/deleting playlists.
+#[derive(Debug, Clone)]
+struct BrowserState {
+    purpose: BrowserPurpose,
+    selected: usize,
+}
+
+/// A ranked local-library match surfaced by the fuzzy search popup.
+#[derive(Debug, Clone)]
+struct FuzzyHit {
+    playlist: String,
+    track_index: usize,
+    title: String,
+    score: i64,
+}
+
 struct AppState {
     playlist_manager: PlaylistManager,
     current_playlist: String,
@@ -34,6 +190,15 @@ struct AppState {
     last_update: Instant,
     search_mode: bool,
     search_input: String,
+    search_results: Vec<FuzzyHit>,
+    search_selected: usize,
+    playback_mode: PlaybackMode,
+    sequence: PlaylistSequence,
+    browser: Option<BrowserState>,
+    creating_playlist: bool,
+    new_playlist_input: String,
+    crossfade_secs: f32,
+    show_settings: bool,
 }
 
 impl AppState {
@@ -53,6 +218,100 @@ impl AppState {
             last_update: Instant::now(),
             search_mode: false,
             search_input: String::new(),
+            search_results: Vec::new(),
+            search_selected: 0,
+            playback_mode: PlaybackMode::Sequential,
+            sequence: PlaylistSequence::default(),
+            browser: None,
+            creating_playlist: false,
+            new_playlist_input: String::new(),
+            crossfade_secs: 0.0,
+            show_settings: false,
+        }
+    }
+
+    /// Sorted playlist names, used both to render the browser popup and to
+    /// resolve the name at the currently highlighted row.
+    fn playlist_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.playlist_manager.playlists.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Playlist names offered by the browser popup for a given `purpose`.
+    /// `DeletePlaylist` hides "default" since it can't be removed.
+    fn browser_items(&self, purpose: BrowserPurpose) -> Vec<String> {
+        let names = self.playlist_names();
+        match purpose {
+            BrowserPurpose::DeletePlaylist => names.into_iter().filter(|n| n != "default").collect(),
+            BrowserPurpose::SwitchPlaylist | BrowserPurpose::AddTrackTo => names,
+        }
+    }
+
+    /// Re-ranks `search_results` against the current `search_input` using
+    /// `fuzzy::score`, searching every playlist (not just the current one).
+    /// A leading `>` switches the popup to YouTube mode, so results are
+    /// cleared rather than scored in that case.
+    fn refresh_search_results(&mut self) {
+        self.search_selected = 0;
+        let query = self.search_input.trim();
+        if query.is_empty() || query.starts_with('>') {
+            self.search_results.clear();
+            return;
+        }
+        let mut hits: Vec<FuzzyHit> = Vec::new();
+        for playlist in self.playlist_manager.playlists.values() {
+            for (i, track) in playlist.tracks.iter().enumerate() {
+                if let Some(score) = fuzzy::score(query, &track.title) {
+                    hits.push(FuzzyHit {
+                        playlist: playlist.name.clone(),
+                        track_index: i,
+                        title: track.title.clone(),
+                        score,
+                    });
+                }
+            }
+        }
+        hits.sort_by(|a, b| b.score.cmp(&a.score));
+        hits.truncate(20);
+        self.search_results = hits;
+    }
+
+    /// Resolves the track index to play next for `len` tracks, given the
+    /// active `PlaybackMode`. `auto` distinguishes a natural track-end
+    /// (where `RepeatOne` replays and `Sequential` stops at the last track)
+    /// from a manual skip (which always moves, even in `RepeatOne`).
+    fn next_index(&mut self, len: usize, auto: bool) -> Option<usize> {
+        match self.playback_mode {
+            PlaybackMode::RepeatOne if auto => Some(self.current_track),
+            PlaybackMode::Shuffle => Some(self.sequence.advance(len)),
+            PlaybackMode::Sequential if auto && self.current_track + 1 >= len => None,
+            _ => Some((self.current_track + 1) % len),
+        }
+    }
+
+    fn previous_index(&mut self, len: usize) -> usize {
+        match self.playback_mode {
+            PlaybackMode::Shuffle => self.sequence.retreat(len),
+            _ => {
+                if self.current_track == 0 {
+                    len - 1
+                } else {
+                    self.current_track - 1
+                }
+            }
+        }
+    }
+
+    /// Read-only version of `next_index(.., auto = true)`, used to tell
+    /// `audio::preload_if_near_end` which file to stage without disturbing
+    /// the shuffle cursor (only an actual advance should consume it).
+    fn peek_next_index(&self, len: usize) -> Option<usize> {
+        match self.playback_mode {
+            PlaybackMode::RepeatOne => Some(self.current_track),
+            PlaybackMode::Shuffle => Some(self.sequence.peek_next(len)),
+            PlaybackMode::Sequential if self.current_track + 1 >= len => None,
+            _ => Some((self.current_track + 1) % len),
         }
     }
 
@@ -73,32 +332,95 @@ impl AppState {
 
     fn next_track(&mut self) {
         let tracks = self.current_tracks();
-        if !tracks.is_empty() {
-            audio::stop_audio();
-            self.current_track = (self.current_track + 1) % tracks.len();
-            self.progress = 0.0;
-            self.is_playing = false;
-            self.is_paused = false;
-            self.status_message = format!("Piste suivante: {}", tracks[self.current_track].title);
-            self.play_current_track();
+        if tracks.is_empty() {
+            return;
         }
+        // Manual skip always moves, even in RepeatOne/Sequential-at-end.
+        let idx = self.next_index(tracks.len(), false).unwrap_or(0);
+        audio::stop_audio();
+        self.current_track = idx;
+        self.progress = 0.0;
+        self.is_playing = false;
+        self.is_paused = false;
+        self.status_message = format!("Piste suivante: {}", tracks[self.current_track].title);
+        self.play_current_track();
     }
 
     fn previous_track(&mut self) {
         let tracks = self.current_tracks();
-        if !tracks.is_empty() {
-            audio::stop_audio();
-            self.current_track = if self.current_track == 0 {
-                tracks.len() - 1
-            } else {
-                self.current_track - 1
-            };
-            self.progress = 0.0;
-            self.is_playing = false;
-            self.is_paused = false;
-            self.status_message = format!("Piste précédente: {}", tracks[self.current_track].title);
-            self.play_current_track();
+        if tracks.is_empty() {
+            return;
         }
+        audio::stop_audio();
+        self.current_track = self.previous_index(tracks.len());
+        self.progress = 0.0;
+        self.is_playing = false;
+        self.is_paused = false;
+        self.status_message = format!("Piste précédente: {}", tracks[self.current_track].title);
+        self.play_current_track();
+    }
+
+    /// Advances (or replays) according to `playback_mode` when a track
+    /// finishes on its own, as opposed to a manual skip.
+    fn advance_on_track_end(&mut self) {
+        let tracks = self.current_tracks();
+        if tracks.is_empty() {
+            return;
+        }
+        match self.next_index(tracks.len(), true) {
+            Some(idx) => {
+                self.current_track = idx;
+                self.progress = 0.0;
+                self.status_message = format!("Piste suivante: {}", tracks[self.current_track].title);
+                // Advance into an already-preloaded track directly (gapless)
+                // rather than stopping and respawning, when mpv staged it.
+                if audio::is_preloaded() && audio::next().is_ok() {
+                    self.is_playing = true;
+                    self.is_paused = false;
+                    // `next()` only clears the preload flag, not `ended` —
+                    // without this the next tick sees the stale flag and
+                    // re-enters this branch, spamming `playlist-next`.
+                    audio::mark_advanced();
+                } else {
+                    self.play_current_track();
+                }
+            }
+            None => {
+                audio::stop_audio();
+                self.is_playing = false;
+                self.progress = 0.0;
+                self.status_message = "Fin de la playlist".to_string();
+            }
+        }
+    }
+
+    /// Starts crossfading into the upcoming track once `crossfade_secs` is
+    /// set and `audio::crossfade_if_near_end` reports we're within that
+    /// window of the current track's end, then commits the advance on our
+    /// side immediately rather than waiting for mpv's `ended` flag — the
+    /// outgoing stream keeps fading on its own process in the background.
+    /// Uses the same read-only lookahead as `audio::preload_if_near_end` so
+    /// Shuffle's cursor is only consumed once the crossfade actually starts.
+    fn maybe_start_crossfade(&mut self, tracks: &[Track]) {
+        if self.crossfade_secs <= 0.0 || tracks.is_empty() {
+            return;
+        }
+        let Some(idx) = self.peek_next_index(tracks.len()) else {
+            return;
+        };
+        let Some(upcoming) = tracks.get(idx) else {
+            return;
+        };
+        if !audio::crossfade_if_near_end(&upcoming.file_path) {
+            return;
+        }
+
+        self.next_index(tracks.len(), true);
+        self.current_track = idx;
+        self.progress = 0.0;
+        self.is_playing = true;
+        self.is_paused = false;
+        self.status_message = format!("Piste suivante: {}", upcoming.title);
     }
 
     fn toggle_play_pause(&mut self) {
@@ -144,18 +466,48 @@ impl AppState {
     fn adjust_volume(&mut self, delta: i8) {
         let new_volume = (self.volume as i8 + delta).clamp(0, 100) as u8;
         self.volume = new_volume;
+        let _ = audio::set_volume(self.volume);
         self.status_message = format!("🔊 Volume: {}%", self.volume);
     }
 
     fn update_progress(&mut self) {
-        if self.is_playing && !self.is_paused {
-            self.progress += 0.001;
-            let tracks = self.current_tracks();
-            if self.progress >= 1.0 && !tracks.is_empty() {
-                self.progress = 0.0;
-                self.next_track();
+        if !self.is_playing || self.is_paused {
+            return;
+        }
+
+        if let (Some(position), Some(total)) = (audio::position(), audio::total_duration()) {
+            let total_secs = total.as_secs_f64();
+            if total_secs > 0.0 {
+                self.progress = (position.as_secs_f64() / total_secs).min(1.0);
             }
         }
+
+        let tracks = self.current_tracks();
+        if self.crossfade_secs > 0.0 {
+            // Crossfade replaces mpv's own gapless playlist as the
+            // transition mechanism (it needs two concurrent processes to
+            // overlap the fade), so skip staging a track there too.
+            self.maybe_start_crossfade(&tracks);
+        } else if !tracks.is_empty() {
+            // mpv owns the actual transition here (it advances its own
+            // internal playlist gaplessly once the preloaded entry plays),
+            // so sync `current_track` from that signal instead of waiting
+            // on `ended` — `eof-reached` never fires for an intra-playlist
+            // switch, only once the whole playlist drains.
+            if audio::take_gapless_transition() {
+                if let Some(idx) = self.next_index(tracks.len(), true) {
+                    self.current_track = idx;
+                    self.progress = 0.0;
+                    self.status_message = format!("Piste suivante: {}", tracks[idx].title);
+                }
+            } else if let Some(upcoming) = self.peek_next_index(tracks.len()).and_then(|i| tracks.get(i)) {
+                audio::preload_if_near_end(&upcoming.file_path);
+            }
+        }
+
+        if audio::get_playback_state().ended && !tracks.is_empty() {
+            self.advance_on_track_end();
+        }
     }
 }
 
@@ -173,6 +525,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     fs::create_dir_all(music_dir)?;
 
     let mut app_state = AppState::new();
+    let _ = audio::set_volume(app_state.volume);
     let _ = app_state.playlist_manager.load_all_from_dir("playlists");
     if app_state.current_tracks().is_empty() {
         load_existing_tracks(&mut app_state, music_dir)?;
@@ -209,16 +562,26 @@ fn load_existing_tracks(app_state: &mut AppState, music_dir: &str) -> io::Result
         for entry in entries.flatten() {
             if let Some(ext) = entry.path().extension() {
                 if ext == "mp3" {
-                    let title = entry.path()
+                    let file_path = entry.path().display().to_string();
+                    let file_stem_title = entry.path()
                         .file_stem()
                         .and_then(|s| s.to_str())
                         .unwrap_or("Titre inconnu")
                         .to_string();
+                    // Tags win over the file stem when present, but a
+                    // missing/corrupt `ffprobe` read still leaves the track
+                    // browsable under its filename.
+                    let local_tags = tags::read_local_tags(&file_path);
                     let track = Track {
-                        title,
-                        file_path: entry.path().display().to_string(),
+                        title: local_tags.as_ref()
+                            .and_then(|t| t.title.clone())
+                            .unwrap_or(file_stem_title),
+                        file_path,
                         url: None,
-                        duration: None,
+                        duration: local_tags.as_ref().and_then(|t| t.duration),
+                        artist: local_tags.as_ref().and_then(|t| t.artist.clone()),
+                        album: local_tags.as_ref().and_then(|t| t.album.clone()),
+                        thumbnail: None,
                     };
                     app_state.add_track_to_current(track);
                     any = true;
@@ -232,28 +595,32 @@ fn load_existing_tracks(app_state: &mut AppState, music_dir: &str) -> io::Result
     Ok(())
 }
 
-fn prompt(question: &str) -> io::Result<String> {
-    print!("{}", question);
-    io::stdout().flush()?;
-    let mut input = String::new();
-    io::stdin().read_line(&mut input)?;
-    Ok(input.trim().to_string())
-}
-
 fn run_app<B: ratatui::backend::Backend>(
     terminal: &mut Terminal<B>,
     app_state: &mut AppState,
     music_dir: &str,
 ) -> io::Result<()> {
+    #[cfg(feature = "mpris")]
+    let mpris_commands = mpris::spawn();
+
     loop {
         if app_state.last_update.elapsed() >= Duration::from_millis(100) {
             app_state.update_progress();
             app_state.last_update = Instant::now();
         }
 
+        #[cfg(feature = "mpris")]
+        handle_mpris_commands(app_state, &mpris_commands);
+
         terminal.draw(|f| {
             if app_state.search_mode {
                 draw_search_popup(f, app_state);
+            } else if app_state.creating_playlist {
+                draw_new_playlist_popup(f, app_state);
+            } else if app_state.browser.is_some() {
+                draw_browser_popup(f, app_state);
+            } else if app_state.show_settings {
+                draw_settings_popup(f, app_state);
             } else {
                 draw_main_ui(f, app_state);
             }
@@ -266,10 +633,26 @@ fn run_app<B: ratatui::backend::Backend>(
             if let Event::Key(key) = event::read()? {
                 if app_state.search_mode {
                     handle_search_input(app_state, key, music_dir)?;
+                } else if app_state.creating_playlist {
+                    handle_new_playlist_input(app_state, key);
+                } else if app_state.browser.is_some() {
+                    handle_browser_input(app_state, key);
+                } else if app_state.show_settings {
+                    handle_settings_input(app_state, key);
                 } else {
                     match key.code {
                         KeyCode::Char('q') | KeyCode::Esc => break,
                         KeyCode::Char('p') | KeyCode::Char(' ') => app_state.toggle_play_pause(),
+                        KeyCode::Right if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                            let position = audio::get_playback_state().position;
+                            let _ = audio::seek_to(position + SEEK_STEP);
+                            app_state.status_message = "⏩ Avance rapide".to_string();
+                        }
+                        KeyCode::Left if key.modifiers.contains(KeyModifiers::SHIFT) => {
+                            let position = audio::get_playback_state().position;
+                            let _ = audio::seek_to(position.saturating_sub(SEEK_STEP));
+                            app_state.status_message = "⏪ Retour rapide".to_string();
+                        }
                         KeyCode::Char('n') | KeyCode::Right => app_state.next_track(),
                         KeyCode::Char('b') | KeyCode::Left => app_state.previous_track(),
                         KeyCode::Char('s') => {
@@ -283,36 +666,42 @@ fn run_app<B: ratatui::backend::Backend>(
                             app_state.progress = 0.0;
                             app_state.status_message = "⏮️ Remis au début".to_string();
                         }
+                        KeyCode::Char('m') => {
+                            app_state.playback_mode = app_state.playback_mode.next();
+                            if app_state.playback_mode == PlaybackMode::Shuffle {
+                                let len = app_state.current_tracks().len();
+                                app_state.sequence.reshuffle(len);
+                            }
+                            app_state.status_message =
+                                format!("🔀 Mode: {}", app_state.playback_mode.label());
+                        }
+                        KeyCode::Char('X') => {
+                            app_state.show_settings = true;
+                        }
                         // Playlists shortcuts
                         KeyCode::Char('P') => {
-                            let name = prompt("Nom de la nouvelle playlist : ")?;
-                            if app_state.playlist_manager.create_playlist(&name) {
-                                app_state.status_message = format!("Playlist '{}' créée", name);
-                            } else {
-                                app_state.status_message = format!("Playlist '{}' existe déjà", name);
-                            }
+                            app_state.creating_playlist = true;
+                            app_state.new_playlist_input.clear();
                         }
                         KeyCode::Char('D') => {
-                            let pl = app_state.current_playlist.clone();
-                            if pl == "default" {
-                                app_state.status_message = "Impossible de supprimer la playlist par défaut".to_string();
-                            } else if app_state.playlist_manager.delete_playlist(&pl) {
-                                app_state.status_message = format!("Playlist '{}' supprimée", pl);
-                                app_state.current_playlist = "default".into();
-                                app_state.current_track = 0;
+                            if app_state.playlist_manager.playlists.len() <= 1 {
+                                app_state.status_message =
+                                    "Aucune autre playlist à supprimer".to_string();
                             } else {
-                                app_state.status_message = format!("Impossible de supprimer '{}'", pl);
+                                app_state.browser = Some(BrowserState {
+                                    purpose: BrowserPurpose::DeletePlaylist,
+                                    selected: 0,
+                                });
                             }
                         }
                         KeyCode::Char('A') => {
-                            let target = prompt("Ajouter la piste courante à quelle playlist ? ")?;
-                            let tracks = app_state.current_tracks();
-                            if let Some(track) = tracks.get(app_state.current_track).cloned() {
-                                if app_state.playlist_manager.add_track_to_playlist(&target, track) {
-                                    app_state.status_message = format!("Ajouté à '{}'", target);
-                                } else {
-                                    app_state.status_message = "Playlist introuvable".to_string();
-                                }
+                            if app_state.current_tracks().is_empty() {
+                                app_state.status_message = "Aucune piste à ajouter".to_string();
+                            } else {
+                                app_state.browser = Some(BrowserState {
+                                    purpose: BrowserPurpose::AddTrackTo,
+                                    selected: 0,
+                                });
                             }
                         }
                         KeyCode::Char('S') => {
@@ -340,15 +729,10 @@ fn run_app<B: ratatui::backend::Backend>(
                             app_state.status_message = msg;
                         }
                         KeyCode::Char('C') => {
-                            let name = prompt("Aller à la playlist : ")?;
-                            if app_state.playlist_manager.playlists.contains_key(&name) {
-                                app_state.current_playlist = name;
-                                app_state.current_track = 0;
-                                app_state.progress = 0.0;
-                                app_state.status_message = "Changement de playlist".to_string();
-                            } else {
-                                app_state.status_message = "Playlist introuvable".to_string();
-                            }
+                            app_state.browser = Some(BrowserState {
+                                purpose: BrowserPurpose::SwitchPlaylist,
+                                selected: 0,
+                            });
                         }
                         _ => {}
                     }
@@ -359,51 +743,274 @@ fn run_app<B: ratatui::backend::Backend>(
     Ok(())
 }
 
+/// Drains any commands the MPRIS D-Bus thread forwarded (media keys,
+/// status-bar widgets) and applies them through the same `AppState`/`audio`
+/// paths the keyboard shortcuts use, then republishes the resulting state
+/// so `PlaybackStatus`/`Metadata`/`Volume`/`Position` stay current.
+#[cfg(feature = "mpris")]
+fn handle_mpris_commands(
+    app_state: &mut AppState,
+    commands: &std::sync::mpsc::Receiver<mpris::MprisCommand>,
+) {
+    while let Ok(command) = commands.try_recv() {
+        match command {
+            mpris::MprisCommand::PlayPause => app_state.toggle_play_pause(),
+            mpris::MprisCommand::Next => app_state.next_track(),
+            mpris::MprisCommand::Previous => app_state.previous_track(),
+            mpris::MprisCommand::Stop => {
+                audio::stop_audio();
+                app_state.is_playing = false;
+                app_state.is_paused = false;
+                app_state.progress = 0.0;
+            }
+            mpris::MprisCommand::Seek(offset_micros) => {
+                let position = audio::get_playback_state().position;
+                let delta = Duration::from_micros(offset_micros.unsigned_abs());
+                let target = if offset_micros >= 0 {
+                    position + delta
+                } else {
+                    position.saturating_sub(delta)
+                };
+                let _ = audio::seek_to(target);
+            }
+            mpris::MprisCommand::SetPosition(position_micros) => {
+                let target = Duration::from_micros(position_micros.max(0) as u64);
+                let _ = audio::seek_to(target);
+            }
+        }
+    }
+
+    let tracks = app_state.current_tracks();
+    let title = tracks
+        .get(app_state.current_track)
+        .map(|t| t.title.clone())
+        .unwrap_or_default();
+    mpris::set_state(mpris::MprisState {
+        playing: app_state.is_playing && !app_state.is_paused,
+        title,
+        track_id: format!("/org/keeplisten/track/{}", app_state.current_track),
+        length_micros: audio::total_duration().map(|d| d.as_micros() as i64).unwrap_or(0),
+        position_micros: audio::position().map(|d| d.as_micros() as i64).unwrap_or(0),
+        volume: app_state.volume as f64 / 100.0,
+    });
+}
+
+/// A leading `>` in `search_input` streams straight from YouTube (the
+/// previous, only behaviour); plain text instead fuzzy-matches local
+/// playlists via `refresh_search_results`, letting the user arrow through
+/// ranked hits and press Enter to jump straight to a track they already have.
 fn handle_search_input(
-    app_state: &mut AppState, 
-    key: crossterm::event::KeyEvent, 
-    music_dir: &str
+    app_state: &mut AppState,
+    key: crossterm::event::KeyEvent,
+    _music_dir: &str
 ) -> io::Result<()> {
     match key.code {
         KeyCode::Enter => {
-            if !app_state.search_input.trim().is_empty() {
-                let query = app_state.search_input.clone();
+            let trimmed = app_state.search_input.trim().to_string();
+            if trimmed.is_empty() {
                 app_state.search_mode = false;
-                app_state.status_message = format!("🔎 Recherche: {}", query);
-                if let Some((url, title)) = youtube::search_first_video(&query) {
-                    if let Ok(file_path) = youtube::download_audio(&url, music_dir) {
+                app_state.search_results.clear();
+                return Ok(());
+            }
+            if let Some(query) = trimmed.strip_prefix('>') {
+                let query = query.trim().to_string();
+                app_state.search_mode = false;
+                app_state.search_results.clear();
+                if query.is_empty() {
+                    return Ok(());
+                }
+                app_state.status_message = format!("🔎 Recherche YouTube: {}", query);
+                // Stream straight from yt-dlp's extracted URL instead of
+                // downloading to `music_dir` first, so playback starts
+                // immediately; `download_audio_with_metadata` remains
+                // available for callers that want a persisted local copy.
+                match youtube::stream_and_play(&query) {
+                    Ok(media) => {
                         let track = Track {
-                            title,
-                            file_path,
-                            url: Some(url),
-                            duration: None,
+                            title: media.title.clone(),
+                            file_path: media.stream_url,
+                            url: Some(query),
+                            duration: media.duration,
+                            artist: media.uploader,
+                            album: None,
+                            thumbnail: media.thumbnail,
                         };
                         app_state.add_track_to_current(track);
-                    } else {
-                        app_state.status_message = "Erreur lors du téléchargement".to_string();
+                        app_state.current_track = app_state.current_tracks().len().saturating_sub(1);
+                        app_state.is_playing = true;
+                        app_state.is_paused = false;
+                        app_state.status_message = format!("▶️ Lecture: {}", media.title);
+                    }
+                    Err(e) => {
+                        app_state.status_message = format!("❌ Erreur lecture: {}", e);
                     }
-                } else {
-                    app_state.status_message = "Aucun résultat trouvé".to_string();
                 }
             } else {
                 app_state.search_mode = false;
+                if let Some(hit) = app_state.search_results.get(app_state.search_selected).cloned() {
+                    app_state.search_results.clear();
+                    app_state.current_playlist = hit.playlist;
+                    app_state.current_track = hit.track_index;
+                    app_state.play_current_track();
+                } else {
+                    app_state.search_results.clear();
+                }
             }
         }
         KeyCode::Esc => {
             app_state.search_mode = false;
             app_state.search_input.clear();
+            app_state.search_results.clear();
+        }
+        KeyCode::Up => {
+            if app_state.search_selected > 0 {
+                app_state.search_selected -= 1;
+            }
+        }
+        KeyCode::Down => {
+            if app_state.search_selected + 1 < app_state.search_results.len() {
+                app_state.search_selected += 1;
+            }
         }
         KeyCode::Backspace => {
             app_state.search_input.pop();
+            app_state.refresh_search_results();
         }
         KeyCode::Char(c) => {
             app_state.search_input.push(c);
+            app_state.refresh_search_results();
         }
         _ => {}
     }
     Ok(())
 }
 
+/// Handles keystrokes while `app_state.browser` holds a navigable list of
+/// playlist names (opened by the `C`/`D`/`A` shortcuts).
+fn handle_browser_input(app_state: &mut AppState, key: crossterm::event::KeyEvent) {
+    let purpose = match app_state.browser.as_ref() {
+        Some(b) => b.purpose,
+        None => return,
+    };
+    let items = app_state.browser_items(purpose);
+
+    if key.code == KeyCode::Esc {
+        app_state.browser = None;
+        return;
+    }
+
+    if let Some(browser) = app_state.browser.as_mut() {
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => {
+                if browser.selected > 0 {
+                    browser.selected -= 1;
+                }
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                if browser.selected + 1 < items.len() {
+                    browser.selected += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if key.code != KeyCode::Enter {
+        return;
+    }
+
+    let selected = app_state.browser.as_ref().map(|b| b.selected).unwrap_or(0);
+    let name = match items.get(selected) {
+        Some(name) => name.clone(),
+        None => {
+            app_state.browser = None;
+            return;
+        }
+    };
+
+    match purpose {
+        BrowserPurpose::SwitchPlaylist => {
+            app_state.current_playlist = name;
+            app_state.current_track = 0;
+            app_state.progress = 0.0;
+            app_state.status_message = "Changement de playlist".to_string();
+        }
+        BrowserPurpose::AddTrackTo => {
+            let tracks = app_state.current_tracks();
+            if let Some(track) = tracks.get(app_state.current_track).cloned() {
+                if app_state.playlist_manager.add_track_to_playlist(&name, track) {
+                    app_state.status_message = format!("Ajouté à '{}'", name);
+                } else {
+                    app_state.status_message = "Playlist introuvable".to_string();
+                }
+            }
+        }
+        BrowserPurpose::DeletePlaylist => {
+            if app_state.playlist_manager.delete_playlist(&name) {
+                app_state.status_message = format!("Playlist '{}' supprimée", name);
+                if app_state.current_playlist == name {
+                    app_state.current_playlist = "default".into();
+                    app_state.current_track = 0;
+                }
+            } else {
+                app_state.status_message = format!("Impossible de supprimer '{}'", name);
+            }
+        }
+    }
+    app_state.browser = None;
+}
+
+/// Handles keystrokes while `app_state.creating_playlist` is set (opened by
+/// the `P` shortcut), mirroring `handle_search_input`'s inline text entry.
+fn handle_new_playlist_input(app_state: &mut AppState, key: crossterm::event::KeyEvent) {
+    match key.code {
+        KeyCode::Enter => {
+            let name = app_state.new_playlist_input.trim().to_string();
+            app_state.creating_playlist = false;
+            if name.is_empty() {
+                return;
+            }
+            if app_state.playlist_manager.create_playlist(&name) {
+                app_state.status_message = format!("Playlist '{}' créée", name);
+            } else {
+                app_state.status_message = format!("Playlist '{}' existe déjà", name);
+            }
+        }
+        KeyCode::Esc => {
+            app_state.creating_playlist = false;
+            app_state.new_playlist_input.clear();
+        }
+        KeyCode::Backspace => {
+            app_state.new_playlist_input.pop();
+        }
+        KeyCode::Char(c) => {
+            app_state.new_playlist_input.push(c);
+        }
+        _ => {}
+    }
+}
+
+/// Handles keystrokes while `app_state.show_settings` is set (opened by the
+/// `X` shortcut). `←`/`→` adjust `crossfade_secs` in half-second steps and
+/// apply immediately, both to keep the popup simple and so the change is
+/// audible on the very next auto-advance.
+fn handle_settings_input(app_state: &mut AppState, key: crossterm::event::KeyEvent) {
+    match key.code {
+        KeyCode::Left | KeyCode::Char('-') => {
+            app_state.crossfade_secs = (app_state.crossfade_secs - 0.5).max(0.0);
+            audio::set_crossfade_secs(app_state.crossfade_secs);
+        }
+        KeyCode::Right | KeyCode::Char('+') => {
+            app_state.crossfade_secs = (app_state.crossfade_secs + 0.5).min(10.0);
+            audio::set_crossfade_secs(app_state.crossfade_secs);
+        }
+        KeyCode::Enter | KeyCode::Esc => {
+            app_state.show_settings = false;
+        }
+        _ => {}
+    }
+}
+
 fn draw_main_ui(f: &mut Frame, app_state: &AppState) {
     let tracks = app_state.current_tracks();
     let chunks = Layout::default()
@@ -416,9 +1023,12 @@ fn draw_main_ui(f: &mut Frame, app_state: &AppState) {
         ])
         .split(f.area());
 
+    let total_duration: Duration = tracks.iter().filter_map(|t| t.duration).sum();
     let header = Paragraph::new(format!(
-        "- Keeplisten -  [Playlist: {}]",
-        app_state.current_playlist
+        "- Keeplisten -  [Playlist: {}]  [Mode: {}]  [Durée totale: {}]",
+        app_state.current_playlist,
+        app_state.playback_mode.label(),
+        format_duration(total_duration)
     ))
     .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
     .alignment(Alignment::Center)
@@ -445,7 +1055,13 @@ fn draw_main_ui(f: &mut Frame, app_state: &AppState) {
             } else {
                 Style::default()
             };
-            ListItem::new(format!("{} {}", symbol, track.title)).style(style)
+            let artist = track.artist.as_deref().unwrap_or("Artiste inconnu");
+            let duration = track.duration.map(format_duration).unwrap_or_else(|| "--:--".to_string());
+            let mut label = format!("{} — {} ({})", artist, track.title, duration);
+            if let Some(album) = &track.album {
+                label.push_str(&format!("  [{}]", album));
+            }
+            ListItem::new(format!("{} {}", symbol, label)).style(style)
         })
         .collect();
 
@@ -481,7 +1097,7 @@ fn draw_player_controls(f: &mut Frame, area: Rect, app_state: &AppState) {
         .ratio(app_state.progress);
     f.render_widget(progress, chunks[0]);
 
-    let controls = Paragraph::new("Space: Play/Pause | ←→: Piste | ↑↓: Volume | S: Recherche | P: Nouvelle Playlist | D: Suppr Playlist | A: Ajout piste | S: Suppr piste | L: Lister | C: Changer | H: Aide | Q: Quitter")
+    let controls = Paragraph::new("Space: Play/Pause | ←→: Piste | Shift+←→: Avance/Retour | ↑↓: Volume | M: Mode | S: Recherche | P: Nouvelle Playlist | D: Suppr Playlist | A: Ajout piste | S: Suppr piste | L: Lister | C: Changer | H: Aide | Q: Quitter")
         .style(Style::default().fg(Color::Gray))
         .alignment(Alignment::Center);
     f.render_widget(controls, chunks[1]);
@@ -493,17 +1109,109 @@ fn draw_player_controls(f: &mut Frame, area: Rect, app_state: &AppState) {
 }
 
 fn draw_search_popup(f: &mut Frame, app_state: &AppState) {
+    draw_main_ui(f, app_state);
+    let has_results = !app_state.search_input.trim_start().starts_with('>');
+    let popup_area = centered_rect(50, if has_results { 50 } else { 20 }, f.area());
+    f.render_widget(Clear, popup_area);
+
+    let title = if app_state.search_input.trim_start().starts_with('>') {
+        "📺 Télécharger depuis YouTube"
+    } else {
+        "🔍 Rechercher (local fuzzy, '>' pour YouTube)"
+    };
+
+    if has_results {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(1)])
+            .split(popup_area);
+
+        let input = Paragraph::new(app_state.search_input.as_str())
+            .style(Style::default().fg(Color::Yellow))
+            .block(Block::default().borders(Borders::ALL).title(title));
+        f.render_widget(input, chunks[0]);
+
+        let items: Vec<ListItem> = app_state
+            .search_results
+            .iter()
+            .enumerate()
+            .map(|(i, hit)| {
+                let style = if i == app_state.search_selected {
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                ListItem::new(format!("[{}] {}", hit.playlist, hit.title)).style(style)
+            })
+            .collect();
+        let results = List::new(items).block(Block::default().borders(Borders::ALL).title("Résultats"));
+        f.render_widget(results, chunks[1]);
+    } else {
+        let input = Paragraph::new(app_state.search_input.as_str())
+            .style(Style::default().fg(Color::Yellow))
+            .block(Block::default().borders(Borders::ALL).title(title));
+        f.render_widget(input, popup_area);
+    }
+}
+
+fn draw_new_playlist_popup(f: &mut Frame, app_state: &AppState) {
     draw_main_ui(f, app_state);
     let popup_area = centered_rect(50, 20, f.area());
     f.render_widget(Clear, popup_area);
-    let input = Paragraph::new(app_state.search_input.as_str())
+    let input = Paragraph::new(app_state.new_playlist_input.as_str())
         .style(Style::default().fg(Color::Yellow))
         .block(Block::default()
             .borders(Borders::ALL)
-            .title("🔍 Rechercher une musique"));
+            .title("📁 Nom de la nouvelle playlist"));
     f.render_widget(input, popup_area);
 }
 
+fn draw_settings_popup(f: &mut Frame, app_state: &AppState) {
+    draw_main_ui(f, app_state);
+    let popup_area = centered_rect(50, 20, f.area());
+    f.render_widget(Clear, popup_area);
+    let text = format!(
+        "Crossfade: {:.1}s (0 = désactivé)\n←/→ pour ajuster, Entrée/Esc pour fermer",
+        app_state.crossfade_secs
+    );
+    let settings = Paragraph::new(text)
+        .style(Style::default().fg(Color::Yellow))
+        .alignment(Alignment::Center)
+        .block(Block::default().borders(Borders::ALL).title("⚙️ Réglages"));
+    f.render_widget(settings, popup_area);
+}
+
+fn draw_browser_popup(f: &mut Frame, app_state: &AppState) {
+    draw_main_ui(f, app_state);
+    let browser = match app_state.browser.as_ref() {
+        Some(b) => b,
+        None => return,
+    };
+    let title = match browser.purpose {
+        BrowserPurpose::SwitchPlaylist => "📂 Changer de playlist",
+        BrowserPurpose::AddTrackTo => "➕ Ajouter à la playlist",
+        BrowserPurpose::DeletePlaylist => "🗑️ Supprimer une playlist",
+    };
+    let items: Vec<ListItem> = app_state
+        .browser_items(browser.purpose)
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let style = if i == browser.selected {
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            ListItem::new(name.as_str()).style(style)
+        })
+        .collect();
+
+    let popup_area = centered_rect(50, 40, f.area());
+    f.render_widget(Clear, popup_area);
+    let list = List::new(items).block(Block::default().borders(Borders::ALL).title(title));
+    f.render_widget(list, popup_area);
+}
+
 fn draw_help_popup(f: &mut Frame) {
     let popup_area = centered_rect(60, 70, f.area());
     f.render_widget(Clear, popup_area);
@@ -516,13 +1224,17 @@ fn draw_help_popup(f: &mut Frame) {
         Line::from("  ←/→ N/B  - Piste précédente/suivante"),
         Line::from("  ↑/↓ +/-  - Volume +/-"),
         Line::from("  R        - Remettre au début"),
-        Line::from("  S        - Rechercher une musique"),
+        Line::from("  M        - Changer de mode (Séquentiel/Répéter tout/Répéter 1/Aléatoire)"),
+        Line::from("  Shift+←→ - Avance/retour de 5s"),
+        Line::from("  S        - Rechercher (local fuzzy, '>texte' pour YouTube)"),
         Line::from("  P        - Nouvelle playlist"),
-        Line::from("  D        - Supprimer playlist"),
-        Line::from("  A        - Ajouter piste à playlist"),
+        Line::from("  D        - Supprimer playlist (liste à choisir)"),
+        Line::from("  A        - Ajouter piste à playlist (liste à choisir)"),
         Line::from("  S        - Supprimer piste"),
         Line::from("  L        - Lister playlists"),
-        Line::from("  C        - Changer de playlist"),
+        Line::from("  C        - Changer de playlist (liste à choisir)"),
+        Line::from("  X        - Réglages (crossfade)"),
+        Line::from("  ↑/↓ j/k  - Naviguer dans une liste, Entrée pour valider, Esc pour annuler"),
         Line::from("  H/F1     - Afficher/masquer cette aide"),
         Line::from("  Q/Esc    - Quitter"),
         Line::from(""),
@@ -0,0 +1,91 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use lazy_static::lazy_static;
+
+/// Playback statistics accumulated over the life of the process.
+#[derive(Debug, Clone, Default)]
+pub struct PlaybackStats {
+    pub tracks_started: u64,
+    pub total_listening_time: Duration,
+    pub skips: u64,
+    pub pause_count: u64,
+    pub now_playing: Option<String>,
+}
+
+/// Destination for `PlaybackStats`, e.g. a push-gateway endpoint or a local
+/// key/value store. Implement this to wire the `metrics` feature up to
+/// whatever backend an embedder wants; `record` is called after every
+/// tracked state transition.
+pub trait MetricsSink: Send + Sync {
+    fn record(&self, stats: &PlaybackStats);
+}
+
+struct State {
+    stats: PlaybackStats,
+    playing_since: Option<Instant>,
+    sink: Option<Box<dyn MetricsSink>>,
+}
+
+lazy_static! {
+    static ref STATE: Mutex<State> = Mutex::new(State {
+        stats: PlaybackStats::default(),
+        playing_since: None,
+        sink: None,
+    });
+}
+
+/// Registers the sink that receives a `PlaybackStats` snapshot after every
+/// tracked transition. Replaces any previously registered sink.
+pub fn set_sink(sink: Box<dyn MetricsSink>) {
+    STATE.lock().unwrap().sink = Some(sink);
+}
+
+pub fn stats() -> PlaybackStats {
+    STATE.lock().unwrap().stats.clone()
+}
+
+pub fn record_track_started(title: &str) {
+    let mut state = STATE.lock().unwrap();
+    state.stats.tracks_started += 1;
+    state.stats.now_playing = Some(title.to_string());
+    state.playing_since = Some(Instant::now());
+    flush(&mut state);
+}
+
+pub fn record_paused() {
+    let mut state = STATE.lock().unwrap();
+    state.stats.pause_count += 1;
+    accumulate_listening_time(&mut state);
+    flush(&mut state);
+}
+
+pub fn record_resumed() {
+    let mut state = STATE.lock().unwrap();
+    state.playing_since = Some(Instant::now());
+    flush(&mut state);
+}
+
+pub fn record_skip() {
+    let mut state = STATE.lock().unwrap();
+    state.stats.skips += 1;
+    flush(&mut state);
+}
+
+pub fn record_stopped() {
+    let mut state = STATE.lock().unwrap();
+    accumulate_listening_time(&mut state);
+    state.stats.now_playing = None;
+    flush(&mut state);
+}
+
+fn accumulate_listening_time(state: &mut State) {
+    if let Some(since) = state.playing_since.take() {
+        state.stats.total_listening_time += since.elapsed();
+    }
+}
+
+fn flush(state: &mut State) {
+    if let Some(sink) = state.sink.as_ref() {
+        sink.record(&state.stats);
+    }
+}
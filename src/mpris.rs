@@ -0,0 +1,227 @@
+//! MPRIS2 (`org.mpris.MediaPlayer2` / `.Player`) integration so desktop
+//! status bars and hardware media keys can see now-playing metadata and
+//! drive playback. The D-Bus service runs on its own thread; `main.rs`'s
+//! render loop only has to drain the receiver returned by `spawn()` once per tick (alongside
+//! `event::poll`) and call `set_state(...)` whenever `AppState` changes,
+//! the same decoupling `audio::subscribe()` uses for playback events.
+
+use std::collections::HashMap;
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use lazy_static::lazy_static;
+use zbus::blocking::{Connection, ConnectionBuilder};
+use zbus::dbus_interface;
+use zbus::zvariant::Value;
+
+/// Commands the desktop environment asked us to perform, forwarded here
+/// from the D-Bus thread so the ratatui render loop stays single-threaded.
+#[derive(Debug, Clone)]
+pub enum MprisCommand {
+    PlayPause,
+    Next,
+    Previous,
+    Stop,
+    Seek(i64),
+    SetPosition(i64),
+}
+
+/// Snapshot of the properties MPRIS exposes, refreshed by `main.rs`
+/// whenever `is_playing`, `current_track`, or `volume` changes.
+#[derive(Debug, Clone, Default)]
+pub struct MprisState {
+    pub playing: bool,
+    pub title: String,
+    pub track_id: String,
+    pub length_micros: i64,
+    pub position_micros: i64,
+    pub volume: f64,
+}
+
+lazy_static! {
+    static ref STATE: Mutex<MprisState> = Mutex::new(MprisState::default());
+    static ref BUS: Mutex<Option<Connection>> = Mutex::new(None);
+}
+
+/// `#[dbus_interface]` implements `zbus::Interface` for the type it's
+/// applied to, so the root `org.mpris.MediaPlayer2` interface needs its own
+/// type — two `#[dbus_interface]` blocks on the same `Player` type would be
+/// two conflicting `Interface` impls for it. `ConnectionBuilder::serve_at`
+/// is called once per type below, registering both interfaces at the same
+/// object path.
+struct MediaPlayer2Root;
+
+#[dbus_interface(name = "org.mpris.MediaPlayer2")]
+impl MediaPlayer2Root {
+    #[dbus_interface(property)]
+    fn can_quit(&self) -> bool {
+        false
+    }
+    #[dbus_interface(property)]
+    fn can_raise(&self) -> bool {
+        false
+    }
+    #[dbus_interface(property)]
+    fn has_track_list(&self) -> bool {
+        false
+    }
+    #[dbus_interface(property)]
+    fn identity(&self) -> String {
+        "keeplisten".to_string()
+    }
+    #[dbus_interface(property)]
+    fn supported_uri_schemes(&self) -> Vec<String> {
+        Vec::new()
+    }
+    #[dbus_interface(property)]
+    fn supported_mime_types(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+struct Player {
+    commands: Sender<MprisCommand>,
+}
+
+#[dbus_interface(name = "org.mpris.MediaPlayer2.Player")]
+impl Player {
+    fn play_pause(&self) {
+        let _ = self.commands.send(MprisCommand::PlayPause);
+    }
+    fn next(&self) {
+        let _ = self.commands.send(MprisCommand::Next);
+    }
+    fn previous(&self) {
+        let _ = self.commands.send(MprisCommand::Previous);
+    }
+    fn stop(&self) {
+        let _ = self.commands.send(MprisCommand::Stop);
+    }
+    fn seek(&self, offset_micros: i64) {
+        let _ = self.commands.send(MprisCommand::Seek(offset_micros));
+    }
+    fn set_position(&self, _track_id: zbus::zvariant::ObjectPath<'_>, position_micros: i64) {
+        let _ = self.commands.send(MprisCommand::SetPosition(position_micros));
+    }
+
+    #[dbus_interface(property)]
+    fn playback_status(&self) -> String {
+        if STATE.lock().unwrap().playing {
+            "Playing".to_string()
+        } else {
+            "Paused".to_string()
+        }
+    }
+
+    #[dbus_interface(property)]
+    fn metadata(&self) -> HashMap<String, Value<'static>> {
+        metadata_map(&STATE.lock().unwrap())
+    }
+
+    #[dbus_interface(property)]
+    fn volume(&self) -> f64 {
+        STATE.lock().unwrap().volume
+    }
+
+    #[dbus_interface(property)]
+    fn position(&self) -> i64 {
+        STATE.lock().unwrap().position_micros
+    }
+}
+
+/// Starts the D-Bus service on a background thread and returns the
+/// receiving end of the command channel; `main.rs`'s render loop should
+/// drain it once per tick (`rx.try_recv()`) the same way it polls input.
+pub fn spawn() -> Receiver<MprisCommand> {
+    let (tx, rx) = channel();
+    thread::spawn(move || {
+        let player = Player { commands: tx };
+        let connection = ConnectionBuilder::session()
+            .ok()
+            .and_then(|b| b.name("org.mpris.MediaPlayer2.keeplisten").ok())
+            .and_then(|b| b.serve_at("/org/mpris/MediaPlayer2", MediaPlayer2Root).ok())
+            .and_then(|b| b.serve_at("/org/mpris/MediaPlayer2", player).ok())
+            .and_then(|b| b.build().ok());
+
+        if let Some(connection) = connection {
+            *BUS.lock().unwrap() = Some(connection);
+            loop {
+                thread::sleep(Duration::from_secs(3600));
+            }
+        }
+    });
+    rx
+}
+
+/// Records the latest player state and tells any listening status bar
+/// which of `PlaybackStatus`, `Metadata`, and `Volume` actually changed.
+/// `main.rs` calls this once per render-loop tick, so the comparison
+/// against the previous state is what keeps `PropertiesChanged` from
+/// firing dozens of times a second while nothing but position moves.
+pub fn set_state(state: MprisState) {
+    let mut current = STATE.lock().unwrap();
+    let changed = changed_properties(&current, &state);
+    *current = state;
+    drop(current);
+    if !changed.is_empty() {
+        notify_properties_changed(&changed);
+    }
+}
+
+fn changed_properties(old: &MprisState, new: &MprisState) -> Vec<&'static str> {
+    let mut changed = Vec::new();
+    if old.playing != new.playing {
+        changed.push("PlaybackStatus");
+    }
+    if old.track_id != new.track_id || old.title != new.title || old.length_micros != new.length_micros {
+        changed.push("Metadata");
+    }
+    if old.volume != new.volume {
+        changed.push("Volume");
+    }
+    changed
+}
+
+fn metadata_map(state: &MprisState) -> HashMap<String, Value<'static>> {
+    let mut map = HashMap::new();
+    map.insert("mpris:trackid".to_string(), Value::from(state.track_id.clone()));
+    map.insert("mpris:length".to_string(), Value::from(state.length_micros));
+    map.insert("xesam:title".to_string(), Value::from(state.title.clone()));
+    map
+}
+
+/// Emits the standard `org.freedesktop.DBus.Properties.PropertiesChanged`
+/// signal for the given MPRIS player property names. Desktop shells that
+/// don't subscribe to it still work fine since they read properties
+/// directly; this just avoids the poll delay for the ones that do.
+fn notify_properties_changed(properties: &[&str]) {
+    let bus = BUS.lock().unwrap();
+    let Some(connection) = bus.as_ref() else {
+        return;
+    };
+    let changed: HashMap<&str, Value> = properties
+        .iter()
+        .filter_map(|name| property_value(name).map(|v| (*name, v)))
+        .collect();
+    let invalidated: Vec<&str> = Vec::new();
+    let _ = connection.emit_signal(
+        None::<&str>,
+        "/org/mpris/MediaPlayer2",
+        "org.freedesktop.DBus.Properties",
+        "PropertiesChanged",
+        &("org.mpris.MediaPlayer2.Player", changed, invalidated),
+    );
+}
+
+fn property_value(name: &str) -> Option<Value<'static>> {
+    let state = STATE.lock().unwrap();
+    match name {
+        "PlaybackStatus" => Some(Value::from(if state.playing { "Playing" } else { "Paused" })),
+        "Metadata" => Some(Value::from(metadata_map(&state))),
+        "Volume" => Some(Value::from(state.volume)),
+        "Position" => Some(Value::from(state.position_micros)),
+        _ => None,
+    }
+}
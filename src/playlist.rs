@@ -2,13 +2,17 @@ use std::collections::HashMap;
 use std::fs;
 use std::io::{self, Write};
 use std::path::Path;
+use std::time::Duration;
 
 #[derive(Debug, Clone)]
 pub struct Track {
     pub title: String,
     pub file_path: String,
     pub url: Option<String>,
-    pub duration: Option<String>,
+    pub duration: Option<Duration>,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub thumbnail: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -158,19 +162,30 @@ impl PlaylistManager {
                     let lines = fs::read_to_string(&path)?;
                     let mut playlist = Playlist::new(name);
                     for line in lines.lines() {
-                        // On ne connaît pas le titre, juste le chemin
                         let file_path = line.trim();
                         if !file_path.is_empty() {
-                            let title = Path::new(file_path)
-                                .file_stem()
-                                .and_then(|s| s.to_str())
-                                .unwrap_or(file_path)
-                                .to_string();
+                            // Re-read tags on reload so saved playlists keep
+                            // their enriched metadata instead of falling
+                            // back to "Artiste inconnu"/"--:--" every time.
+                            let tags = crate::tags::read_local_tags(file_path);
+                            let title = tags
+                                .as_ref()
+                                .and_then(|t| t.title.clone())
+                                .unwrap_or_else(|| {
+                                    Path::new(file_path)
+                                        .file_stem()
+                                        .and_then(|s| s.to_str())
+                                        .unwrap_or(file_path)
+                                        .to_string()
+                                });
                             playlist.add_track(Track {
                                 title,
                                 file_path: file_path.to_string(),
                                 url: None,
-                                duration: None,
+                                duration: tags.as_ref().and_then(|t| t.duration),
+                                artist: tags.as_ref().and_then(|t| t.artist.clone()),
+                                album: tags.as_ref().and_then(|t| t.album.clone()),
+                                thumbnail: None,
                             });
                         }
                     }
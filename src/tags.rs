@@ -0,0 +1,57 @@
+use std::process::Command;
+use std::time::Duration;
+
+/// ID3/container tag metadata read from a local audio file, mirroring
+/// `youtube::ExtractedMedia` but sourced from the file itself instead of
+/// yt-dlp's JSON.
+#[derive(Debug, Clone, Default)]
+pub struct LocalTags {
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub duration: Option<Duration>,
+}
+
+/// Runs `ffprobe -show_format` against `path` and pulls the `title`/
+/// `artist`/`album` tags plus the container's reported duration. Best-effort:
+/// returns `None` on any failure (missing `ffprobe`, unreadable file)
+/// rather than an error; an untagged file yields `Some` with the tag fields
+/// left `None`. Either way callers fall back to the file stem as the title
+/// like before this existed.
+pub fn read_local_tags(path: &str) -> Option<LocalTags> {
+    let output = Command::new("ffprobe")
+        .arg("-v")
+        .arg("quiet")
+        .arg("-print_format")
+        .arg("json")
+        .arg("-show_format")
+        .arg(path)
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).ok()?;
+    let format = json.get("format")?;
+    let tags = format.get("tags");
+
+    let tag = |key: &str| -> Option<String> {
+        tags.and_then(|t| t.get(key).or_else(|| t.get(key.to_uppercase())))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+    };
+
+    let duration = format["duration"]
+        .as_str()
+        .and_then(|s| s.parse::<f64>().ok())
+        .map(Duration::from_secs_f64);
+
+    Some(LocalTags {
+        title: tag("title"),
+        artist: tag("artist"),
+        album: tag("album"),
+        duration,
+    })
+}
@@ -145,6 +145,87 @@ pub fn download_audio(link: &str, output_dir: &str) -> Result<String, Box<dyn Er
     download_audio_with_progress(link, output_dir, None::<fn(f32)>)
 }
 
+/// Metadata captured alongside a downloaded file, parsed from the same
+/// yt-dlp invocation's `--print-json` info dict rather than a second `-J`
+/// round-trip.
+#[derive(Debug, Clone)]
+pub struct DownloadedTrack {
+    pub file_path: String,
+    pub title: String,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub duration: Option<Duration>,
+    pub thumbnail: Option<String>,
+}
+
+/// Downloads `link` like `download_audio`, but also extracts artist, album,
+/// duration and thumbnail from yt-dlp's own info dict via `--print-json`.
+pub fn download_audio_with_metadata(link: &str, output_dir: &str) -> Result<DownloadedTrack, Box<dyn Error>> {
+    if link.trim().is_empty() {
+        return Err("URL vide fournie".into());
+    }
+    if output_dir.trim().is_empty() {
+        return Err("Répertoire de sortie vide".into());
+    }
+
+    std::fs::create_dir_all(output_dir)?;
+
+    let output_template = format!("{}/%(title).100s.%(ext)s", output_dir);
+    let files_before = count_mp3_files(output_dir)?;
+
+    let output = Command::new("yt-dlp")
+        .arg("-x")
+        .arg("--audio-format")
+        .arg("mp3")
+        .arg("--audio-quality")
+        .arg("0")
+        .arg("-o")
+        .arg(&output_template)
+        .arg("--no-warnings")
+        .arg("--restrict-filenames")
+        .arg("--print-json")
+        .arg(link)
+        .output()?;
+
+    if !output.status.success() {
+        return Err(format!("❌ yt-dlp a échoué lors du téléchargement (code: {:?})", output.status.code()).into());
+    }
+
+    let file_path = find_newest_mp3(output_dir, files_before)?;
+    if !std::path::Path::new(&file_path).exists() {
+        return Err("❌ Fichier téléchargé introuvable après yt-dlp".into());
+    }
+
+    // `--print-json` writes the info dict as the last line of stdout once
+    // the download (and any post-processing) finishes.
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json = stdout
+        .lines()
+        .rev()
+        .find_map(|line| serde_json::from_str::<serde_json::Value>(line).ok());
+
+    let (title, artist, album, duration, thumbnail) = match &json {
+        Some(json) => (
+            json["title"].as_str().map(|s| s.to_string()),
+            json["artist"].as_str().or_else(|| json["uploader"].as_str()).map(|s| s.to_string()),
+            json["album"].as_str().map(|s| s.to_string()),
+            json["duration"].as_f64().map(Duration::from_secs_f64),
+            json["thumbnail"].as_str().map(|s| s.to_string()),
+        ),
+        None => (None, None, None, None, None),
+    };
+
+    let title = title.unwrap_or_else(|| {
+        std::path::Path::new(&file_path)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("Titre inconnu")
+            .to_string()
+    });
+
+    Ok(DownloadedTrack { file_path, title, artist, album, duration, thumbnail })
+}
+
 fn extract_percentage(line: &str) -> Option<String> {
     // Look for patterns like "[download] 45.2% of 3.45MiB at 1.23MiB/s ETA 00:02"
     if let Some(start) = line.find("] ") {
@@ -272,8 +353,112 @@ pub fn check_yt_dlp_available() -> bool {
 
 /// Check if URL is a valid YouTube URL
 pub fn is_youtube_url(url: &str) -> bool {
-    url.contains("youtube.com/watch") || 
-    url.contains("youtu.be/") || 
+    url.contains("youtube.com/watch") ||
+    url.contains("youtu.be/") ||
     url.contains("youtube.com/playlist") ||
     url.contains("music.youtube.com")
 }
+
+/// Structured metadata plus a direct audio stream URL, extracted from
+/// `yt-dlp -J` so mpv can start playing immediately without downloading
+/// first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExtractedMedia {
+    pub title: String,
+    pub uploader: Option<String>,
+    pub duration: Option<Duration>,
+    pub thumbnail: Option<String>,
+    pub stream_url: String,
+}
+
+/// Runs `yt-dlp -J` against a URL (or `ytsearch1:<query>` for a plain
+/// search) and deserializes the result into `ExtractedMedia`, choosing the
+/// best audio-only format for `stream_url`. Parse failures surface as
+/// `YoutubeError::ParseError` rather than a generic `Box<dyn Error>`.
+pub fn extract_info(query_or_url: &str) -> crate::error::Result<ExtractedMedia> {
+    let target = if is_youtube_url(query_or_url) {
+        query_or_url.to_string()
+    } else {
+        format!("ytsearch1:{}", query_or_url)
+    };
+
+    let output = Command::new("yt-dlp")
+        .arg(&target)
+        .arg("-J")
+        .arg("--no-warnings")
+        .output()
+        .map_err(|_| crate::error::YoutubeError::YtDlpNotFound)?;
+
+    if !output.status.success() {
+        return Err(crate::error::YoutubeError::SearchFailed(query_or_url.to_string()).into());
+    }
+
+    let raw = String::from_utf8_lossy(&output.stdout);
+    let mut json: serde_json::Value = serde_json::from_str(&raw)
+        .map_err(|e| crate::error::YoutubeError::ParseError(e.to_string()))?;
+
+    // `ytsearch:`/`--flat-playlist` results wrap the real entry in "entries".
+    if let Some(entry) = json
+        .get_mut("entries")
+        .and_then(|e| e.as_array_mut())
+        .and_then(|a| if a.is_empty() { None } else { Some(a.remove(0)) })
+    {
+        json = entry;
+    }
+
+    parse_extracted_media(&json)
+}
+
+fn parse_extracted_media(json: &serde_json::Value) -> crate::error::Result<ExtractedMedia> {
+    let title = json["title"]
+        .as_str()
+        .ok_or_else(|| crate::error::YoutubeError::ParseError("missing title field".to_string()))?
+        .to_string();
+    let uploader = json["uploader"].as_str().map(|s| s.to_string());
+    let duration = json["duration"].as_f64().map(Duration::from_secs_f64);
+    let thumbnail = json["thumbnail"].as_str().map(|s| s.to_string());
+    let stream_url = best_audio_url(json)
+        .ok_or_else(|| crate::error::YoutubeError::ParseError("no audio stream url found".to_string()))?;
+
+    Ok(ExtractedMedia {
+        title,
+        uploader,
+        duration,
+        thumbnail,
+        stream_url,
+    })
+}
+
+/// Picks the highest-bitrate audio-only entry from yt-dlp's `formats`
+/// array, falling back to the top-level `url` field when there is no
+/// `formats` array or none of its audio-only entries carries a `url`.
+fn best_audio_url(json: &serde_json::Value) -> Option<String> {
+    if let Some(formats) = json["formats"].as_array() {
+        let best = formats
+            .iter()
+            .filter(|f| f["vcodec"].as_str() == Some("none"))
+            .max_by(|a, b| {
+                let a_abr = a["abr"].as_f64().unwrap_or(0.0);
+                let b_abr = b["abr"].as_f64().unwrap_or(0.0);
+                a_abr.partial_cmp(&b_abr).unwrap_or(std::cmp::Ordering::Equal)
+            });
+        if let Some(url) = best.and_then(|f| f["url"].as_str()) {
+            return Some(url.to_string());
+        }
+    }
+    json["url"].as_str().map(|s| s.to_string())
+}
+
+/// Extracts `query_or_url` and starts it playing directly through mpv,
+/// skipping the download-to-disk step entirely. Since yt-dlp already knows
+/// the track's real duration, it's applied to `PlaybackState` immediately
+/// instead of waiting on the IPC `duration` property.
+pub fn stream_and_play(query_or_url: &str) -> crate::error::Result<ExtractedMedia> {
+    let media = extract_info(query_or_url)?;
+    crate::audio::play_audio(&media.stream_url)
+        .map_err(|e| crate::error::AudioError::PlaybackFailed(e.to_string()))?;
+    if let Some(duration) = media.duration {
+        crate::audio::set_known_duration(duration);
+    }
+    Ok(media)
+}